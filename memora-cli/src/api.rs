@@ -0,0 +1,80 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fact {
+    pub text: String,
+    #[serde(default)]
+    pub fact_type: Option<String>,
+    #[serde(default)]
+    pub activation: Option<f32>,
+    #[serde(default)]
+    pub context: Option<String>,
+    #[serde(default)]
+    pub occurred_start: Option<String>,
+    #[serde(default)]
+    pub occurred_end: Option<String>,
+    #[serde(default)]
+    pub event_date: Option<String>,
+    #[serde(default)]
+    pub mentioned_at: Option<String>,
+    #[serde(default)]
+    pub document_id: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TraceStage {
+    pub name: String,
+    pub elapsed_ms: f64,
+    #[serde(default)]
+    pub unit_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TraceInfo {
+    #[serde(default)]
+    pub total_time: Option<f64>,
+    #[serde(default)]
+    pub activation_count: Option<u64>,
+    #[serde(default)]
+    pub stages: Vec<TraceStage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResponse {
+    pub results: Vec<Fact>,
+    #[serde(default)]
+    pub trace: Option<TraceInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThinkResponse {
+    pub text: String,
+    #[serde(default)]
+    pub based_on: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Agent {
+    pub agent_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonalityTraits {
+    pub openness: f32,
+    pub conscientiousness: f32,
+    pub extraversion: f32,
+    pub agreeableness: f32,
+    pub neuroticism: f32,
+    pub bias_strength: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentProfile {
+    pub agent_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub background: String,
+    pub personality: PersonalityTraits,
+}
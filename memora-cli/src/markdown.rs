@@ -0,0 +1,155 @@
+//! Minimal terminal renderer for the markdown that LLM-generated think responses and
+//! fact text frequently contain: headings, bullet lists, inline code and fenced code
+//! blocks. Plain text round-trips unchanged so piping to a file or another tool
+//! isn't polluted by styling decisions made here.
+use colored::*;
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use std::io::IsTerminal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Heading,
+    Bold,
+    Emphasis,
+}
+
+fn style_text(styles: &[Style], text: &str) -> String {
+    let mut out = text.to_string();
+    for style in styles {
+        out = match style {
+            Style::Heading => out.bright_yellow().bold().to_string(),
+            Style::Bold => out.bold().to_string(),
+            Style::Emphasis => out.italic().to_string(),
+        };
+    }
+    out
+}
+
+fn render_code_span(code: &str) -> String {
+    format!(" {} ", code).on_bright_black().bright_white().to_string()
+}
+
+/// Renders `text` as styled terminal output, or returns `None` if it contains no
+/// markdown constructs worth rendering (headings, lists, code, emphasis) so the
+/// caller can fall back to its plain-text styling.
+pub fn render_markdown(text: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut styles: Vec<Style> = Vec::new();
+    let mut list_depth: usize = 0;
+    let mut in_code_block = false;
+    let mut saw_markdown = false;
+
+    for event in Parser::new(text) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                saw_markdown = true;
+                let marker = match level {
+                    HeadingLevel::H1 => "#",
+                    HeadingLevel::H2 => "##",
+                    _ => "###",
+                };
+                out.push_str(&format!("{} ", marker).bright_yellow().bold().to_string());
+                styles.push(Style::Heading);
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                styles.pop();
+                out.push('\n');
+            }
+            Event::Start(Tag::Strong) => {
+                saw_markdown = true;
+                styles.push(Style::Bold);
+            }
+            Event::End(TagEnd::Strong) => {
+                styles.pop();
+            }
+            Event::Start(Tag::Emphasis) => {
+                saw_markdown = true;
+                styles.push(Style::Emphasis);
+            }
+            Event::End(TagEnd::Emphasis) => {
+                styles.pop();
+            }
+            Event::Start(Tag::List(_)) => {
+                saw_markdown = true;
+                list_depth += 1;
+            }
+            Event::End(TagEnd::List(_)) => {
+                list_depth = list_depth.saturating_sub(1);
+            }
+            Event::Start(Tag::Item) => {
+                out.push_str(&"  ".repeat(list_depth.max(1)));
+                out.push_str(&"•".bright_black().to_string());
+                out.push(' ');
+            }
+            Event::End(TagEnd::Item) => out.push('\n'),
+            Event::Start(Tag::CodeBlock(_)) => {
+                saw_markdown = true;
+                in_code_block = true;
+                out.push_str(&"  ┌─── code ───".bright_black().to_string());
+                out.push('\n');
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                out.push_str(&"  └────────────".bright_black().to_string());
+                out.push('\n');
+            }
+            Event::Code(code) => {
+                saw_markdown = true;
+                out.push_str(&render_code_span(&code));
+            }
+            Event::Text(t) => {
+                if in_code_block {
+                    for line in t.lines() {
+                        out.push_str(&"  │ ".bright_black().to_string());
+                        out.push_str(&line.bright_cyan().to_string());
+                        out.push('\n');
+                    }
+                } else {
+                    out.push_str(&style_text(&styles, &t));
+                }
+            }
+            Event::SoftBreak => out.push(' '),
+            Event::HardBreak => out.push('\n'),
+            Event::End(TagEnd::Paragraph) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    if saw_markdown {
+        Some(out.trim_end_matches('\n').to_string())
+    } else {
+        None
+    }
+}
+
+/// Whether markdown rendering should default to on: only when stdout is a TTY, so
+/// piped output stays plain and script-friendly.
+pub fn default_enabled() -> bool {
+    std::io::stdout().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_renders_as_none() {
+        assert_eq!(render_markdown("just a sentence, no markdown here"), None);
+    }
+
+    #[test]
+    fn heading_is_detected_as_markdown() {
+        assert!(render_markdown("# Heading\n\nbody").is_some());
+    }
+
+    #[test]
+    fn inline_code_is_detected_as_markdown() {
+        let rendered = render_markdown("run `cargo test` to check").expect("should detect code span");
+        assert!(rendered.contains("cargo test"));
+    }
+
+    #[test]
+    fn bullet_list_is_detected_as_markdown() {
+        assert!(render_markdown("- one\n- two").is_some());
+    }
+}
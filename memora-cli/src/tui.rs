@@ -0,0 +1,441 @@
+//! Interactive full-screen browser for memory, gated behind the `tui` feature and
+//! exposed as the `memora tui` subcommand. Reuses the same `api` types and the same
+//! colored/emoji rendering vocabulary as the plain `println!`-based CLI in `ui.rs`,
+//! just laid out across ratatui panes instead of scrolling stdout.
+#![cfg(feature = "tui")]
+
+use crate::api::{Agent, AgentProfile, Fact, SearchResponse, ThinkResponse};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::error::Error;
+use std::io;
+use std::time::Duration;
+
+/// Which pane currently has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    Search,
+    Agents,
+    Profile,
+}
+
+fn fact_type_color(fact_type: &str) -> Color {
+    match fact_type {
+        "world" => Color::Cyan,
+        "agent" => Color::Magenta,
+        "opinion" => Color::Yellow,
+        _ => Color::White,
+    }
+}
+
+fn fact_type_emoji(fact_type: &str) -> &'static str {
+    match fact_type {
+        "world" => "🌍",
+        "agent" => "🤖",
+        "opinion" => "💭",
+        _ => "📝",
+    }
+}
+
+/// Mutable state for the interactive session. Search and "think" calls are supplied
+/// as closures by the caller so this module stays free of any HTTP/client concerns.
+pub struct TuiApp<'a> {
+    query: String,
+    results: Vec<Fact>,
+    result_state: ListState,
+    agents: Vec<Agent>,
+    agent_state: ListState,
+    profile: Option<AgentProfile>,
+    think_response: Option<ThinkResponse>,
+    pane: Pane,
+    status: Option<String>,
+    search_fn: ApiCall<'a, SearchResponse>,
+    think_fn: ApiCall<'a, ThinkResponse>,
+    profile_fn: ApiCall<'a, AgentProfile>,
+}
+
+/// A caller-supplied lookup (search/think/profile) keyed by a query string, boxed so
+/// `TuiApp` can hold all three kinds behind one shape without a type parameter per call.
+type ApiCall<'a, T> = Box<dyn FnMut(&str) -> Result<T, Box<dyn Error>> + 'a>;
+
+impl<'a> TuiApp<'a> {
+    pub fn new(
+        agents: Vec<Agent>,
+        search_fn: impl FnMut(&str) -> Result<SearchResponse, Box<dyn Error>> + 'a,
+        think_fn: impl FnMut(&str) -> Result<ThinkResponse, Box<dyn Error>> + 'a,
+        profile_fn: impl FnMut(&str) -> Result<AgentProfile, Box<dyn Error>> + 'a,
+    ) -> Self {
+        Self {
+            query: String::new(),
+            results: Vec::new(),
+            result_state: ListState::default(),
+            agents,
+            agent_state: ListState::default(),
+            profile: None,
+            think_response: None,
+            pane: Pane::Search,
+            status: None,
+            search_fn: Box::new(search_fn),
+            think_fn: Box::new(think_fn),
+            profile_fn: Box::new(profile_fn),
+        }
+    }
+
+    fn selected_fact(&self) -> Option<&Fact> {
+        self.result_state.selected().and_then(|i| self.results.get(i))
+    }
+
+    fn run_search(&mut self) {
+        match (self.search_fn)(&self.query) {
+            Ok(response) => {
+                self.results = response.results;
+                self.result_state.select(if self.results.is_empty() { None } else { Some(0) });
+                self.status = Some(format!("{} results", self.results.len()));
+            }
+            Err(err) => self.status = Some(format!("search failed: {err}")),
+        }
+    }
+
+    fn trigger_think(&mut self) {
+        let Some(fact) = self.selected_fact().cloned() else { return };
+        match (self.think_fn)(&fact.text) {
+            Ok(response) => self.think_response = Some(response),
+            Err(err) => self.status = Some(format!("think failed: {err}")),
+        }
+    }
+
+    fn load_profile(&mut self) {
+        let Some(agent_id) = self
+            .agent_state
+            .selected()
+            .and_then(|i| self.agents.get(i))
+            .map(|a| a.agent_id.clone())
+        else {
+            return;
+        };
+        match (self.profile_fn)(&agent_id) {
+            Ok(profile) => self.profile = Some(profile),
+            Err(err) => self.status = Some(format!("profile lookup failed: {err}")),
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        let state = match self.pane {
+            Pane::Search => &mut self.result_state,
+            Pane::Agents => &mut self.agent_state,
+            Pane::Profile => return,
+        };
+        let len = match self.pane {
+            Pane::Search => self.results.len(),
+            Pane::Agents => self.agents.len(),
+            Pane::Profile => 0,
+        };
+        if len == 0 {
+            return;
+        }
+        let current = state.selected().unwrap_or(0) as i32;
+        let next = (current + delta).clamp(0, len as i32 - 1) as usize;
+        state.select(Some(next));
+    }
+}
+
+/// Runs the interactive TUI until the user presses `q` or `Esc`.
+pub fn run(mut app: TuiApp) -> Result<(), Box<dyn Error>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = event_loop(&mut terminal, &mut app);
+
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut TuiApp,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if !event::poll(Duration::from_millis(200))? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => return Ok(()),
+                KeyCode::Tab => {
+                    app.pane = match app.pane {
+                        Pane::Search => Pane::Agents,
+                        Pane::Agents => Pane::Profile,
+                        Pane::Profile => Pane::Search,
+                    }
+                }
+                KeyCode::Down => app.move_selection(1),
+                KeyCode::Up => app.move_selection(-1),
+                KeyCode::Enter => match app.pane {
+                    Pane::Search => app.trigger_think(),
+                    Pane::Agents => app.load_profile(),
+                    Pane::Profile => {}
+                },
+                KeyCode::Backspace if app.pane == Pane::Search => {
+                    app.query.pop();
+                }
+                KeyCode::Char(c) if app.pane == Pane::Search => {
+                    app.query.push(c);
+                }
+                KeyCode::F(5) if app.pane == Pane::Search => app.run_search(),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, app: &TuiApp) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(10), Constraint::Length(8)])
+        .split(frame.area());
+
+    draw_search_box(frame, rows[0], app);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(40), Constraint::Percentage(30)])
+        .split(rows[1]);
+
+    draw_agents_pane(frame, columns[0], app);
+    draw_results_pane(frame, columns[1], app);
+    draw_detail_pane(frame, columns[2], app);
+
+    draw_think_pane(frame, rows[2], app);
+}
+
+fn draw_search_box(frame: &mut Frame, area: Rect, app: &TuiApp) {
+    let title = if app.pane == Pane::Search { "Search (typing)" } else { "Search" };
+    let paragraph = Paragraph::new(app.query.as_str())
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_agents_pane(frame: &mut Frame, area: Rect, app: &TuiApp) {
+    let items: Vec<ListItem> = app
+        .agents
+        .iter()
+        .map(|agent| ListItem::new(agent.agent_id.clone()))
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Agents"))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, area, &mut app.agent_state.clone());
+}
+
+fn draw_results_pane(frame: &mut Frame, area: Rect, app: &TuiApp) {
+    let items: Vec<ListItem> = app
+        .results
+        .iter()
+        .map(|fact| {
+            let fact_type = fact.fact_type.as_deref().unwrap_or("unknown");
+            let line = Line::from(vec![
+                Span::raw(format!("{} ", fact_type_emoji(fact_type))),
+                Span::styled(fact.text.clone(), Style::default().fg(fact_type_color(fact_type))),
+            ]);
+            ListItem::new(line)
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!("Results ({})", app.results.len())))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, area, &mut app.result_state.clone());
+}
+
+fn draw_detail_pane(frame: &mut Frame, area: Rect, app: &TuiApp) {
+    let block = Block::default().borders(Borders::ALL).title("Detail");
+    // Key off the focused pane rather than `Option` presence: a profile loaded once
+    // while the Agents pane had focus must not linger over the Search pane's facts.
+    let text = match app.pane {
+        Pane::Agents | Pane::Profile => match &app.profile {
+            Some(profile) => render_profile(profile),
+            None => "Select an agent and press Enter to load its profile.".to_string(),
+        },
+        Pane::Search => match app.selected_fact() {
+            Some(fact) => render_fact_detail(fact),
+            None => "Select a fact or an agent to see details.".to_string(),
+        },
+    };
+    frame.render_widget(Paragraph::new(text).block(block), area);
+}
+
+fn render_fact_detail(fact: &Fact) -> String {
+    let mut lines = vec![fact.text.clone()];
+    if let Some(context) = &fact.context {
+        lines.push(format!("Context: {context}"));
+    }
+    if let Some(start) = &fact.occurred_start {
+        lines.push(format!("Occurred: {start}"));
+    }
+    if let Some(document_id) = &fact.document_id {
+        lines.push(format!("Document: {document_id}"));
+    }
+    lines.join("\n")
+}
+
+fn render_profile(profile: &AgentProfile) -> String {
+    let traits = [
+        ("Openness", profile.personality.openness),
+        ("Conscientiousness", profile.personality.conscientiousness),
+        ("Extraversion", profile.personality.extraversion),
+        ("Agreeableness", profile.personality.agreeableness),
+        ("Neuroticism", profile.personality.neuroticism),
+    ];
+    let mut lines = vec![format!("{} ({})", profile.name, profile.agent_id)];
+    for (name, value) in traits {
+        let bar_length = 20;
+        let filled = (value * bar_length as f32) as usize;
+        lines.push(format!(
+            "{:<18} [{}{}] {:.0}%",
+            name,
+            "█".repeat(filled),
+            "░".repeat(bar_length - filled),
+            value * 100.0
+        ));
+    }
+    lines.join("\n")
+}
+
+fn draw_think_pane(frame: &mut Frame, area: Rect, app: &TuiApp) {
+    let text = match &app.think_response {
+        Some(response) => response.text.clone(),
+        None => app
+            .status
+            .clone()
+            .unwrap_or_else(|| "Press Enter on a fact to think about it.".to_string()),
+    };
+    let block = Block::default().borders(Borders::ALL).title("Think");
+    frame.render_widget(Paragraph::new(text).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::PersonalityTraits;
+
+    fn stub_fact() -> Fact {
+        Fact {
+            text: "f".to_string(),
+            fact_type: None,
+            activation: None,
+            context: None,
+            occurred_start: None,
+            occurred_end: None,
+            event_date: None,
+            mentioned_at: None,
+            document_id: None,
+            labels: Vec::new(),
+        }
+    }
+
+    fn make_app() -> TuiApp<'static> {
+        TuiApp::new(
+            Vec::new(),
+            |_| Ok(SearchResponse { results: Vec::new(), trace: None }),
+            |_| Ok(ThinkResponse { text: String::new(), based_on: Vec::new() }),
+            |_| {
+                Ok(AgentProfile {
+                    agent_id: "a".to_string(),
+                    name: "a".to_string(),
+                    background: String::new(),
+                    personality: PersonalityTraits {
+                        openness: 0.0,
+                        conscientiousness: 0.0,
+                        extraversion: 0.0,
+                        agreeableness: 0.0,
+                        neuroticism: 0.0,
+                        bias_strength: 0.0,
+                    },
+                })
+            },
+        )
+    }
+
+    #[test]
+    fn move_selection_on_empty_list_does_nothing() {
+        let mut app = make_app();
+        app.pane = Pane::Search;
+        app.move_selection(1);
+        assert_eq!(app.result_state.selected(), None);
+    }
+
+    #[test]
+    fn move_selection_on_single_item_stays_at_zero() {
+        let mut app = make_app();
+        app.pane = Pane::Search;
+        app.results = vec![stub_fact()];
+        app.result_state.select(Some(0));
+        app.move_selection(1);
+        assert_eq!(app.result_state.selected(), Some(0));
+        app.move_selection(-1);
+        assert_eq!(app.result_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn move_selection_clamps_at_upper_bound() {
+        let mut app = make_app();
+        app.pane = Pane::Search;
+        app.results = vec![stub_fact(), stub_fact(), stub_fact()];
+        app.result_state.select(Some(0));
+        app.move_selection(10);
+        assert_eq!(app.result_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn move_selection_clamps_at_lower_bound() {
+        let mut app = make_app();
+        app.pane = Pane::Search;
+        app.results = vec![stub_fact(), stub_fact(), stub_fact()];
+        app.result_state.select(Some(2));
+        app.move_selection(-10);
+        assert_eq!(app.result_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn move_selection_on_agents_pane_moves_agent_state() {
+        let mut app = make_app();
+        app.pane = Pane::Agents;
+        app.agents = vec![Agent { agent_id: "a".to_string() }, Agent { agent_id: "b".to_string() }];
+        app.agent_state.select(Some(0));
+        app.move_selection(1);
+        assert_eq!(app.agent_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn move_selection_on_profile_pane_is_noop() {
+        let mut app = make_app();
+        app.pane = Pane::Profile;
+        app.results = vec![stub_fact()];
+        app.result_state.select(Some(0));
+        app.move_selection(1);
+        assert_eq!(app.result_state.selected(), Some(0));
+    }
+}
@@ -1,8 +1,28 @@
 use crate::api::{Agent, AgentProfile, Fact, PersonalityTraits, SearchResponse, ThinkResponse, TraceInfo};
+use crate::markdown;
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::cmp::Ordering;
 use std::io::{self, Write};
 
+fn fact_type_color(fact_type: &str) -> &'static str {
+    match fact_type {
+        "world" => "cyan",
+        "agent" => "magenta",
+        "opinion" => "yellow",
+        _ => "white",
+    }
+}
+
+fn fact_type_emoji(fact_type: &str) -> &'static str {
+    match fact_type {
+        "world" => "🌍",
+        "agent" => "🤖",
+        "opinion" => "💭",
+        _ => "📝",
+    }
+}
+
 pub fn print_banner() {
     println!("{}", "╔══════════════════════════════════════════════════╗".bright_cyan());
     println!("{}", "║            MEMORA - Memory CLI                   ║".bright_cyan());
@@ -16,22 +36,54 @@ pub fn print_section_header(title: &str) {
     println!();
 }
 
-pub fn print_fact(fact: &Fact, show_activation: bool) {
-    let fact_type = fact.fact_type.as_deref().unwrap_or("unknown");
+/// What to do with a fact whose `labels` match (or default to) a moderated action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelAction {
+    Show,
+    Warn,
+    Hide,
+}
 
-    let type_color = match fact_type {
-        "world" => "cyan",
-        "agent" => "magenta",
-        "opinion" => "yellow",
-        _ => "white",
-    };
+/// Maps labels to a display action, resolved per-fact at print time. Unlisted labels
+/// on an `opinion` fact default to `Warn`; everything else defaults to `Show`.
+#[derive(Debug, Clone, Default)]
+pub struct LabelPolicy {
+    rules: Vec<(String, LabelAction)>,
+}
 
-    let prefix = match fact_type {
-        "world" => "🌍",
-        "agent" => "🤖",
-        "opinion" => "💭",
-        _ => "📝",
-    };
+impl LabelPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `label` to `action`, e.g. an allow-listed label mapped to `LabelAction::Show`.
+    pub fn with_rule(mut self, label: impl Into<String>, action: LabelAction) -> Self {
+        self.rules.push((label.into(), action));
+        self
+    }
+
+    /// Resolves the action for `fact`, forcing `Show` when `show_labeled` is set.
+    pub fn resolve(&self, fact: &Fact, show_labeled: bool) -> LabelAction {
+        if fact.labels.is_empty() || show_labeled {
+            return LabelAction::Show;
+        }
+        for label in &fact.labels {
+            if let Some((_, action)) = self.rules.iter().find(|(l, _)| l == label) {
+                return *action;
+            }
+        }
+        if fact.fact_type.as_deref() == Some("opinion") {
+            LabelAction::Warn
+        } else {
+            LabelAction::Show
+        }
+    }
+}
+
+pub fn print_fact(fact: &Fact, show_activation: bool, render_markdown: bool, label_action: LabelAction) {
+    let fact_type = fact.fact_type.as_deref().unwrap_or("unknown");
+    let type_color = fact_type_color(fact_type);
+    let prefix = fact_type_emoji(fact_type);
 
     print!("{} ", prefix);
     print!("{}", format!("[{}]", fact_type.to_uppercase()).color(type_color).bold());
@@ -43,7 +95,34 @@ pub fn print_fact(fact: &Fact, show_activation: bool) {
     }
 
     println!();
-    println!("  {}", fact.text);
+
+    match label_action {
+        LabelAction::Hide => {
+            // Callers must filter Hide facts out before calling print_fact (see
+            // print_search_results); this arm exists so a Hide action never leaks
+            // labeled content if a future caller forgets to filter.
+            println!("  {} {}",
+                "⚠".bright_yellow().bold(),
+                "Content hidden by label policy".bright_yellow(),
+            );
+            println!();
+            return;
+        }
+        LabelAction::Warn => {
+            println!("  {} {}",
+                "⚠".bright_yellow().bold(),
+                format!("Content labeled [{}] — pass --show-labeled to view", fact.labels.join(", ")).bright_yellow(),
+            );
+            println!();
+            return;
+        }
+        LabelAction::Show => {}
+    }
+
+    match render_markdown.then(|| markdown::render_markdown(&fact.text)).flatten() {
+        Some(rendered) => println!("  {}", rendered.replace('\n', "\n  ")),
+        None => println!("  {}", fact.text),
+    }
 
     // Show context if available
     if let Some(context) = &fact.context {
@@ -82,29 +161,170 @@ pub fn print_fact(fact: &Fact, show_activation: bool) {
     println!();
 }
 
-pub fn print_search_results(response: &SearchResponse, show_trace: bool) {
-    let results = &response.results;
-    print_section_header(&format!("Search Results ({})", results.len()));
+/// Field results can be sorted by in `print_search_results`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    Activation,
+    MentionedAt,
+    OccurredStart,
+    FactType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// How results should be clustered together before printing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    FactType,
+    DocumentId,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchDisplayOptions {
+    pub sort_field: Option<SortField>,
+    pub sort_order: SortOrder,
+    pub group_by: Option<GroupBy>,
+    pub render_markdown: bool,
+    pub label_policy: LabelPolicy,
+    pub show_labeled: bool,
+}
+
+impl Default for SearchDisplayOptions {
+    fn default() -> Self {
+        Self {
+            sort_field: None,
+            sort_order: SortOrder::Descending,
+            group_by: None,
+            render_markdown: false,
+            label_policy: LabelPolicy::default(),
+            show_labeled: false,
+        }
+    }
+}
+
+/// Compares two optional values, always sorting `None` last regardless of `order`.
+fn compare_with_nones_last<T: PartialOrd>(a: Option<T>, b: Option<T>, order: SortOrder) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let ord = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            match order {
+                SortOrder::Ascending => ord,
+                SortOrder::Descending => ord.reverse(),
+            }
+        }
+    }
+}
+
+fn print_group_header(group_by: GroupBy, key: &str, count: usize) {
+    match group_by {
+        GroupBy::FactType => {
+            let color = fact_type_color(key);
+            let emoji = fact_type_emoji(key);
+            println!("{} {} {}",
+                emoji,
+                format!("[{}]", key.to_uppercase()).color(color).bold(),
+                format!("({})", count).bright_black(),
+            );
+        }
+        GroupBy::DocumentId => {
+            println!("{} {}",
+                format!("📄 Document: {}", key).bright_cyan().bold(),
+                format!("({})", count).bright_black(),
+            );
+        }
+    }
+    println!();
+}
+
+pub fn print_search_results(
+    response: &SearchResponse,
+    show_trace: bool,
+    trace_verbosity: TraceVerbosity,
+    display: &SearchDisplayOptions,
+) {
+    let mut results: Vec<&Fact> = response.results.iter().collect();
+
+    if let Some(field) = display.sort_field {
+        results.sort_by(|a, b| match field {
+            SortField::Activation => compare_with_nones_last(a.activation, b.activation, display.sort_order),
+            SortField::MentionedAt => {
+                compare_with_nones_last(a.mentioned_at.clone(), b.mentioned_at.clone(), display.sort_order)
+            }
+            SortField::OccurredStart => {
+                compare_with_nones_last(a.occurred_start.clone(), b.occurred_start.clone(), display.sort_order)
+            }
+            SortField::FactType => compare_with_nones_last(a.fact_type.clone(), b.fact_type.clone(), display.sort_order),
+        });
+    }
+
+    let actions: Vec<LabelAction> = results
+        .iter()
+        .map(|f| display.label_policy.resolve(f, display.show_labeled))
+        .collect();
+    let hidden_count = actions.iter().filter(|a| **a == LabelAction::Hide).count();
+
+    let header = if hidden_count > 0 {
+        format!("Search Results ({}) ({} hidden)", results.len(), hidden_count)
+    } else {
+        format!("Search Results ({})", results.len())
+    };
+    print_section_header(&header);
+
+    let visible: Vec<(&Fact, LabelAction)> = results
+        .iter()
+        .copied()
+        .zip(actions)
+        .filter(|(_, action)| *action != LabelAction::Hide)
+        .collect();
 
     if results.is_empty() {
         println!("{}", "  No results found.".bright_black());
+    } else if let Some(group_by) = display.group_by {
+        let mut groups: Vec<(String, Vec<(&Fact, LabelAction)>)> = Vec::new();
+        for &(fact, action) in &visible {
+            let key = match group_by {
+                GroupBy::FactType => fact.fact_type.clone().unwrap_or_else(|| "unknown".to_string()),
+                GroupBy::DocumentId => fact.document_id.clone().unwrap_or_else(|| "unknown".to_string()),
+            };
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some(group) => group.1.push((fact, action)),
+                None => groups.push((key, vec![(fact, action)])),
+            }
+        }
+        for (key, facts) in &groups {
+            print_group_header(group_by, key, facts.len());
+            for (i, (fact, action)) in facts.iter().enumerate() {
+                println!("{}", format!("  Result #{}", i + 1).bright_black());
+                print_fact(fact, true, display.render_markdown, *action);
+            }
+        }
     } else {
-        for (i, fact) in results.iter().enumerate() {
+        for (i, (fact, action)) in visible.iter().enumerate() {
             println!("{}", format!("  Result #{}", i + 1).bright_black());
-            print_fact(fact, true);
+            print_fact(fact, true, display.render_markdown, *action);
         }
     }
 
     if show_trace {
         if let Some(trace) = &response.trace {
-            print_trace_info(trace);
+            print_trace_info(trace, trace_verbosity);
         }
     }
 }
 
-pub fn print_think_response(response: &ThinkResponse) {
+pub fn print_think_response(response: &ThinkResponse, render_markdown: bool) {
     println!();
-    println!("{}", response.text.bright_white());
+    match render_markdown.then(|| markdown::render_markdown(&response.text)).flatten() {
+        Some(rendered) => println!("{}", rendered),
+        None => println!("{}", response.text.bright_white()),
+    }
     println!();
 
     if !response.based_on.is_empty() {
@@ -112,15 +332,79 @@ pub fn print_think_response(response: &ThinkResponse) {
     }
 }
 
-pub fn print_trace_info(trace: &TraceInfo) {
+/// Controls how much detail `print_trace_info` renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceVerbosity {
+    /// Just the total time.
+    Quiet,
+    /// Total time and activation count, plus the per-stage breakdown table.
+    Normal,
+    /// Everything `Normal` shows, plus per-stage unit counts.
+    Verbose,
+}
+
+/// Renders a `█▓░`-style bar scaled to `fraction` (clamped to `[0.0, 1.0]`), matching
+/// the bar style used by `print_profile`.
+fn render_bar(fraction: f32, width: usize) -> String {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let filled = (fraction * width as f32) as usize;
+    let empty = width - filled;
+    format!("{}{}", "█".repeat(filled), "░".repeat(empty))
+}
+
+pub fn print_trace_info(trace: &TraceInfo, verbosity: TraceVerbosity) {
     print_section_header("Trace Information");
 
     if let Some(time) = trace.total_time {
         println!("  ⏱️  Total time: {}", format!("{:.2}ms", time).bright_green());
     }
 
-    if let Some(count) = trace.activation_count {
-        println!("  📊 Activation count: {}", count.to_string().bright_green());
+    if verbosity != TraceVerbosity::Quiet {
+        if let Some(count) = trace.activation_count {
+            println!("  📊 Activation count: {}", count.to_string().bright_green());
+        }
+
+        if !trace.stages.is_empty() {
+            println!();
+            let name_width = trace.stages.iter().map(|s| s.name.len()).max().unwrap_or(8).max(8);
+            let stages_total: f64 = trace.stages.iter().map(|s| s.elapsed_ms).sum();
+
+            for stage in &trace.stages {
+                let share = trace.total_time
+                    .filter(|total| *total > 0.0)
+                    .map(|total| (stage.elapsed_ms / total) as f32)
+                    .unwrap_or(0.0);
+                let bar = render_bar(share, 20);
+                print!("    {:<width$} [{}] {:>5.1}%  {:.2}ms",
+                    stage.name,
+                    bar.bright_green(),
+                    share * 100.0,
+                    stage.elapsed_ms,
+                    width = name_width,
+                );
+                if verbosity == TraceVerbosity::Verbose {
+                    if let Some(count) = stage.unit_count {
+                        print!("  {}", format!("({} units)", count).bright_black());
+                    }
+                }
+                println!();
+            }
+
+            if let Some(total) = trace.total_time {
+                let unaccounted = total - stages_total;
+                if unaccounted > 0.01 {
+                    let share = (unaccounted / total) as f32;
+                    let bar = render_bar(share, 20);
+                    println!("    {:<width$} [{}] {:>5.1}%  {:.2}ms",
+                        "(unaccounted)".bright_black(),
+                        bar.bright_black(),
+                        share * 100.0,
+                        unaccounted,
+                        width = name_width,
+                    );
+                }
+            }
+        }
     }
 
     println!();
@@ -388,3 +672,86 @@ pub fn print_personality_delta(old: &PersonalityTraits, new: &PersonalityTraits)
     println!("  {}", "(how much personality shapes opinions)".bright_black());
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fact(fact_type: Option<&str>, labels: &[&str]) -> Fact {
+        Fact {
+            text: "text".to_string(),
+            fact_type: fact_type.map(|s| s.to_string()),
+            activation: None,
+            context: None,
+            occurred_start: None,
+            occurred_end: None,
+            event_date: None,
+            mentioned_at: None,
+            document_id: None,
+            labels: labels.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn render_bar_scales_fill_to_fraction() {
+        assert_eq!(render_bar(0.0, 10), "░".repeat(10));
+        assert_eq!(render_bar(1.0, 10), "█".repeat(10));
+        assert_eq!(render_bar(0.5, 10), format!("{}{}", "█".repeat(5), "░".repeat(5)));
+    }
+
+    #[test]
+    fn render_bar_clamps_out_of_range_fractions() {
+        assert_eq!(render_bar(-1.0, 4), "░".repeat(4));
+        assert_eq!(render_bar(2.0, 4), "█".repeat(4));
+    }
+
+    #[test]
+    fn label_policy_defaults_opinion_facts_with_labels_to_warn() {
+        let policy = LabelPolicy::new();
+        let f = fact(Some("opinion"), &["sensitive"]);
+        assert_eq!(policy.resolve(&f, false), LabelAction::Warn);
+    }
+
+    #[test]
+    fn label_policy_defaults_non_opinion_facts_to_show() {
+        let policy = LabelPolicy::new();
+        let f = fact(Some("world"), &["sensitive"]);
+        assert_eq!(policy.resolve(&f, false), LabelAction::Show);
+    }
+
+    #[test]
+    fn label_policy_unlabeled_facts_always_show() {
+        let policy = LabelPolicy::new();
+        let f = fact(Some("opinion"), &[]);
+        assert_eq!(policy.resolve(&f, false), LabelAction::Show);
+    }
+
+    #[test]
+    fn label_policy_explicit_rule_overrides_default() {
+        let policy = LabelPolicy::new().with_rule("nsfw", LabelAction::Hide);
+        let f = fact(Some("opinion"), &["nsfw"]);
+        assert_eq!(policy.resolve(&f, false), LabelAction::Hide);
+    }
+
+    #[test]
+    fn label_policy_show_labeled_forces_show() {
+        let policy = LabelPolicy::new().with_rule("nsfw", LabelAction::Hide);
+        let f = fact(Some("opinion"), &["nsfw"]);
+        assert_eq!(policy.resolve(&f, true), LabelAction::Show);
+    }
+
+    #[test]
+    fn compare_with_nones_last_sorts_missing_values_after_present_ones_both_orders() {
+        assert_eq!(compare_with_nones_last(Some(1), None, SortOrder::Ascending), Ordering::Less);
+        assert_eq!(compare_with_nones_last(None, Some(1), SortOrder::Ascending), Ordering::Greater);
+        assert_eq!(compare_with_nones_last(Some(1), None, SortOrder::Descending), Ordering::Less);
+        assert_eq!(compare_with_nones_last(None, Some(1), SortOrder::Descending), Ordering::Greater);
+        assert_eq!(compare_with_nones_last::<i32>(None, None, SortOrder::Descending), Ordering::Equal);
+    }
+
+    #[test]
+    fn compare_with_nones_last_respects_order_for_present_values() {
+        assert_eq!(compare_with_nones_last(Some(1), Some(2), SortOrder::Ascending), Ordering::Less);
+        assert_eq!(compare_with_nones_last(Some(1), Some(2), SortOrder::Descending), Ordering::Greater);
+    }
+}